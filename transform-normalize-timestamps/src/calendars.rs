@@ -0,0 +1,234 @@
+//! Conversion of the non-Gregorian calendar formats recognized by `time_patterns` into
+//! proleptic Gregorian dates, so they can be normalized onto the same timeline as everything
+//! else.
+
+use chrono::{Duration, NaiveDate};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref JAPANESE_ERA: regex::Regex =
+        regex::Regex::new(r"^(令和|平成|昭和|大正|明治)(\d{1,2})年(\d{1,2})月(\d{1,2})日$")
+            .expect("JAPANESE_ERA pattern must compile");
+}
+
+/// Gregorian year in which each Japanese era began.
+fn era_start_year(era: &str) -> Option<i32> {
+    match era {
+        "令和" => Some(2019), // Reiwa
+        "平成" => Some(1989), // Heisei
+        "昭和" => Some(1926), // Showa
+        "大正" => Some(1912), // Taisho
+        "明治" => Some(1868), // Meiji
+        _ => None,
+    }
+}
+
+/// Convert a Thai Buddhist calendar date (`THAI_CALENDAR`, e.g. `2568-05-19`) to Gregorian by
+/// subtracting the 543-year offset between the Buddhist Era and the Common Era.
+fn thai_to_gregorian(input: &str) -> Option<NaiveDate> {
+    let mut parts = input.splitn(3, '-');
+    let year: i32 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+
+    NaiveDate::from_ymd_opt(year - 543, month, day)
+}
+
+/// Convert a Japanese era date (`JAPANESE_CALENDAR`, e.g. `令和7年5月19日`) to Gregorian:
+/// `gregorian_year = era_start + era_year - 1`.
+fn japanese_to_gregorian(input: &str) -> Option<NaiveDate> {
+    let caps = JAPANESE_ERA.captures(input)?;
+    let era_start = era_start_year(&caps[1])?;
+    let era_year: i32 = caps[2].parse().ok()?;
+    let month: u32 = caps[3].parse().ok()?;
+    let day: u32 = caps[4].parse().ok()?;
+
+    NaiveDate::from_ymd_opt(era_start + era_year - 1, month, day)
+}
+
+/// Convert an Islamic (Hijri) civil calendar date (`ISLAMIC_CALENDAR`, e.g. `1446-11-19`) to
+/// Gregorian using the tabular civil algorithm: Hijri date -> Julian Day -> Gregorian date via
+/// the Fliegel-Van Flandern inverse.
+fn islamic_to_gregorian(input: &str) -> Option<NaiveDate> {
+    let mut parts = input.splitn(3, '-');
+    let h_year: i64 = parts.next()?.parse().ok()?;
+    let h_month: i64 = parts.next()?.parse().ok()?;
+    let h_day: i64 = parts.next()?.parse().ok()?;
+
+    let jd = h_day as f64
+        + (29.5 * (h_month - 1) as f64).ceil()
+        + (h_year - 1) as f64 * 354.0
+        + ((3 + 11 * h_year) as f64 / 30.0).floor()
+        + 1948439.5;
+
+    // `jd` always lands on a half-integer day boundary, so `jd + 0.5` is the integer Julian
+    // Day Number expected by the Fliegel-Van Flandern inverse below.
+    let l = (jd + 0.5) as i64 + 68569;
+    let n = (4 * l) / 146097;
+    let l = l - (146097 * n + 3) / 4;
+    let i = (4000 * (l + 1)) / 1461001;
+    let l = l - (1461 * i) / 4 + 31;
+    let j = (80 * l) / 2447;
+    let day = l - (2447 * j) / 80;
+    let l = j / 11;
+    let month = j + 2 - 12 * l;
+    let year = 100 * (n - 49) + i + l;
+
+    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+}
+
+/// Month names of the Indian national (Saka) calendar, in order.
+const INDIAN_MONTHS: [&str; 12] = [
+    "Chaitra",
+    "Vaisakha",
+    "Jyaishtha",
+    "Ashadha",
+    "Sravana",
+    "Bhadra",
+    "Asvina",
+    "Kartika",
+    "Agrahayana",
+    "Pausha",
+    "Magha",
+    "Phalguna",
+];
+
+fn is_gregorian_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Length of the given (0-indexed) Indian national calendar month: `Chaitra` is 30 days, or 31
+/// in a Gregorian leap year; the next five months are 31 days; the rest are 30.
+fn indian_month_length(month_index: usize, gregorian_leap: bool) -> i64 {
+    match month_index {
+        0 if gregorian_leap => 31,
+        0 => 30,
+        1..=5 => 31,
+        _ => 30,
+    }
+}
+
+/// Convert an Indian national calendar date (`INDIAN_CALENDAR`, e.g. `1947 Chaitra 1`) to
+/// Gregorian: `Chaitra 1` falls on 22 March of `saka_year + 78` (21 March in a Gregorian leap
+/// year), and every later day in the Saka year is that many days further on.
+fn indian_to_gregorian(input: &str) -> Option<NaiveDate> {
+    let mut parts = input.splitn(3, ' ');
+    let saka_year: i32 = parts.next()?.parse().ok()?;
+    let month_name = parts.next()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+
+    let month_index = INDIAN_MONTHS.iter().position(|m| *m == month_name)?;
+    let gregorian_year = saka_year + 78;
+    let leap = is_gregorian_leap_year(gregorian_year);
+    let new_year_day = if leap { 21 } else { 22 };
+    let new_year = NaiveDate::from_ymd_opt(gregorian_year, 3, new_year_day)?;
+
+    let days_before: i64 = (0..month_index).map(|i| indian_month_length(i, leap)).sum();
+    new_year.checked_add_signed(Duration::days(days_before + (day - 1)))
+}
+
+/// Which non-Gregorian calendar a matched value belongs to, corresponding to one of the
+/// `*_CALENDAR` format names in `time_patterns::TIMESTAMP_FORMATS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarKind {
+    Thai,
+    Japanese,
+    Islamic,
+    Hebrew,
+    Indian,
+    Chinese,
+}
+
+/// Map a `*_CALENDAR` format name (as returned by `identify_timestamp_format`) to the
+/// `CalendarKind` that parses it.
+pub fn calendar_kind_for_format(name: &str) -> Option<CalendarKind> {
+    match name {
+        "THAI_CALENDAR" => Some(CalendarKind::Thai),
+        "JAPANESE_CALENDAR" => Some(CalendarKind::Japanese),
+        "ISLAMIC_CALENDAR" => Some(CalendarKind::Islamic),
+        "HEBREW_CALENDAR" => Some(CalendarKind::Hebrew),
+        "INDIAN_CALENDAR" => Some(CalendarKind::Indian),
+        "CHINESE_CALENDAR" => Some(CalendarKind::Chinese),
+        _ => None,
+    }
+}
+
+/// Convert a value matched against one of the non-Gregorian calendar formats into a proleptic
+/// Gregorian date.
+///
+/// `HEBREW_CALENDAR` and `CHINESE_CALENDAR` are documented stubs: the Hebrew calendar's
+/// leap-month rules and the Chinese lunisolar calendar's astronomically-determined month
+/// boundaries aren't implemented yet, so both always return `None`.
+pub fn to_gregorian(input: &str, calendar: CalendarKind) -> Option<NaiveDate> {
+    match calendar {
+        CalendarKind::Thai => thai_to_gregorian(input),
+        CalendarKind::Japanese => japanese_to_gregorian(input),
+        CalendarKind::Islamic => islamic_to_gregorian(input),
+        CalendarKind::Indian => indian_to_gregorian(input),
+        CalendarKind::Hebrew | CalendarKind::Chinese => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thai_buddhist_era_subtracts_543_years() {
+        assert_eq!(
+            to_gregorian("2568-05-19", CalendarKind::Thai),
+            NaiveDate::from_ymd_opt(2025, 5, 19)
+        );
+    }
+
+    #[test]
+    fn test_japanese_reiwa_era_year_one_is_era_start_year() {
+        assert_eq!(
+            to_gregorian("令和7年5月19日", CalendarKind::Japanese),
+            NaiveDate::from_ymd_opt(2025, 5, 19)
+        );
+        assert_eq!(
+            to_gregorian("令和1年5月1日", CalendarKind::Japanese),
+            NaiveDate::from_ymd_opt(2019, 5, 1)
+        );
+    }
+
+    #[test]
+    fn test_japanese_unknown_era_token_is_none() {
+        assert_eq!(to_gregorian("西暦7年5月19日", CalendarKind::Japanese), None);
+    }
+
+    #[test]
+    fn test_islamic_tabular_civil_conversion() {
+        // Golden value for the tabular civil algorithm itself (not a moon-sighting-based
+        // real-world Hijri date, which this deliberately doesn't attempt to match).
+        assert_eq!(
+            to_gregorian("1446-11-19", CalendarKind::Islamic),
+            NaiveDate::from_ymd_opt(2025, 5, 18)
+        );
+    }
+
+    #[test]
+    fn test_indian_national_calendar_new_year_day() {
+        // Matches `indian_to_gregorian`'s own doc-comment example: Chaitra 1, 1947 Saka falls on
+        // 22 March 2025 (a non-leap Gregorian year).
+        assert_eq!(
+            to_gregorian("1947 Chaitra 1", CalendarKind::Indian),
+            NaiveDate::from_ymd_opt(2025, 3, 22)
+        );
+    }
+
+    #[test]
+    fn test_indian_national_calendar_later_day_in_month() {
+        assert_eq!(
+            to_gregorian("1947 Chaitra 15", CalendarKind::Indian),
+            NaiveDate::from_ymd_opt(2025, 4, 5)
+        );
+    }
+
+    #[test]
+    fn test_hebrew_and_chinese_calendars_are_unimplemented_stubs() {
+        assert_eq!(to_gregorian("5785-05-19", CalendarKind::Hebrew), None);
+        assert_eq!(to_gregorian("2025年5月19日", CalendarKind::Chinese), None);
+    }
+}
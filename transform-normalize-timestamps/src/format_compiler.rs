@@ -0,0 +1,350 @@
+//! Compiles Java/strftime-style pattern strings (e.g. `yyyy-MM-dd'T'HH:mm:ss.SSSXXX`) into a
+//! `CompiledFormat`, so users can teach the crate new timestamp shapes without editing
+//! `time_patterns`'s hardcoded table.
+
+use std::fmt;
+
+/// Whether a numeric component is zero-padded to a fixed width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding {
+    Zero,
+    None,
+}
+
+fn padding_for(run_length: usize) -> Padding {
+    if run_length == 2 {
+        Padding::Zero
+    } else {
+        Padding::None
+    }
+}
+
+/// A single parsed piece of a pattern string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Component {
+    /// Literal text to match verbatim (from quoted sections or non-letter runs).
+    Literal(String),
+    /// `y`: 4 letters = full year, 2 = last-two-digits year.
+    Year { full: bool },
+    Month { padding: Padding },
+    Day { padding: Padding },
+    /// `H`: 24-hour clock.
+    Hour24 { padding: Padding },
+    /// `h`: 12-hour clock.
+    Hour12 { padding: Padding },
+    Minute { padding: Padding },
+    Second { padding: Padding },
+    /// `S`: fractional seconds; `digits` is the run length (e.g. `SSS` -> 3).
+    FractionalSecond { digits: usize },
+    /// `E`: weekday name.
+    WeekdayName,
+    /// `w`: ISO week number.
+    IsoWeek { padding: Padding },
+    /// `X`/`Z`: zone offset.
+    ZoneOffset,
+}
+
+/// A top-level item in a compiled format: either a component that must match, or an entire
+/// `[...]` section that parses if it can and is otherwise skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    Required(Component),
+    Optional(Vec<Component>),
+}
+
+/// The result of compiling a pattern string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledFormat {
+    pub items: Vec<Item>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    /// A `'` quoted literal was opened but never closed.
+    UnterminatedLiteral,
+    /// A `[` optional section was opened but never closed (or closed without being opened).
+    UnbalancedOptionalSection,
+    /// A letter with no known component mapping (e.g. `yyyy-QQ`'s `Q`).
+    UnknownLetter(char),
+    /// The regex generated from a compiled format failed to compile (should not happen for
+    /// well-formed components; a signal something in `to_regex_pattern` needs fixing).
+    InvalidGeneratedRegex,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::UnterminatedLiteral => write!(f, "unterminated quoted literal"),
+            FormatError::UnbalancedOptionalSection => write!(f, "unbalanced '[' / ']' section"),
+            FormatError::UnknownLetter(c) => write!(f, "unknown pattern letter '{c}'"),
+            FormatError::InvalidGeneratedRegex => write!(f, "generated regex failed to compile"),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+fn component_for_letter(letter: char, run_length: usize) -> Result<Component, FormatError> {
+    Ok(match letter {
+        'y' => Component::Year { full: run_length != 2 },
+        'M' => Component::Month { padding: padding_for(run_length) },
+        'd' => Component::Day { padding: padding_for(run_length) },
+        'H' => Component::Hour24 { padding: padding_for(run_length) },
+        'h' => Component::Hour12 { padding: padding_for(run_length) },
+        'm' => Component::Minute { padding: padding_for(run_length) },
+        's' => Component::Second { padding: padding_for(run_length) },
+        'S' => Component::FractionalSecond { digits: run_length },
+        'E' => Component::WeekdayName,
+        'w' => Component::IsoWeek { padding: padding_for(run_length) },
+        'X' | 'Z' => Component::ZoneOffset,
+        other => return Err(FormatError::UnknownLetter(other)),
+    })
+}
+
+/// Tokenize a bracket-free slice of a pattern into components: runs of the same letter become
+/// one component each, `'...'` sections become literals (`''` is a literal single quote), and
+/// any other run of non-letter characters becomes a literal.
+fn parse_components(pattern: &str) -> Result<Vec<Component>, FormatError> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut components = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\'' {
+            let mut j = i + 1;
+            let mut literal = String::new();
+            loop {
+                if j >= chars.len() {
+                    return Err(FormatError::UnterminatedLiteral);
+                }
+                if chars[j] == '\'' {
+                    if chars.get(j + 1) == Some(&'\'') {
+                        literal.push('\'');
+                        j += 2;
+                        continue;
+                    }
+                    j += 1;
+                    break;
+                }
+                literal.push(chars[j]);
+                j += 1;
+            }
+            // `''` with nothing between the outer quote and the escape means a literal quote.
+            if literal.is_empty() {
+                literal.push('\'');
+            }
+            components.push(Component::Literal(literal));
+            i = j;
+        } else if c.is_ascii_alphabetic() {
+            let start = i;
+            while i < chars.len() && chars[i] == c {
+                i += 1;
+            }
+            components.push(component_for_letter(c, i - start)?);
+        } else {
+            let start = i;
+            while i < chars.len() && !chars[i].is_ascii_alphabetic() && chars[i] != '\'' {
+                i += 1;
+            }
+            components.push(Component::Literal(chars[start..i].iter().collect()));
+        }
+    }
+
+    Ok(drop_redundant_dot_before_fractional(components))
+}
+
+/// `FractionalSecond`'s own regex (`\.\d{n}`) and chrono pattern (`%.nf` or `%.f`) already account for
+/// the `.` that conventionally precedes it (e.g. `ss.SSS`), but `parse_components` tokenizes
+/// that `.` as its own `Literal(".")` first. Left in place, the generated pattern would require
+/// two consecutive dots. Drop a literal `.` immediately followed by a `FractionalSecond`.
+fn drop_redundant_dot_before_fractional(components: Vec<Component>) -> Vec<Component> {
+    let mut out: Vec<Component> = Vec::with_capacity(components.len());
+    let mut iter = components.into_iter().peekable();
+
+    while let Some(component) = iter.next() {
+        let is_redundant_dot = matches!(&component, Component::Literal(text) if text == ".")
+            && matches!(iter.peek(), Some(Component::FractionalSecond { .. }));
+        if !is_redundant_dot {
+            out.push(component);
+        }
+    }
+
+    out
+}
+
+fn find_matching_bracket(chars: &[char], open: usize) -> Result<usize, FormatError> {
+    let mut depth = 0usize;
+    for (offset, &c) in chars[open..].iter().enumerate() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(open + offset);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(FormatError::UnbalancedOptionalSection)
+}
+
+/// Compile a Java/strftime-style pattern string into a `CompiledFormat`.
+///
+/// `[...]` sections are optional (parse-succeed-or-skip); everything else is required.
+pub fn compile_format(pattern: &str) -> Result<CompiledFormat, FormatError> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut items = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == ']' {
+            return Err(FormatError::UnbalancedOptionalSection);
+        }
+
+        if chars[i] == '[' {
+            let close = find_matching_bracket(&chars, i)?;
+            let inner: String = chars[i + 1..close].iter().collect();
+            items.push(Item::Optional(parse_components(&inner)?));
+            i = close + 1;
+        } else {
+            let next_bracket = chars[i..]
+                .iter()
+                .position(|&c| c == '[' || c == ']')
+                .map(|offset| i + offset)
+                .unwrap_or(chars.len());
+            let segment: String = chars[i..next_bracket].iter().collect();
+            items.extend(parse_components(&segment)?.into_iter().map(Item::Required));
+            i = next_bracket;
+        }
+    }
+
+    Ok(CompiledFormat { items })
+}
+
+fn component_regex(component: &Component) -> String {
+    match component {
+        Component::Literal(text) => regex::escape(text),
+        Component::Year { full: true } => r"\d{4}".to_string(),
+        Component::Year { full: false } => r"\d{2}".to_string(),
+        Component::Month { padding: Padding::Zero } => r"(?:0[1-9]|1[0-2])".to_string(),
+        Component::Month { padding: Padding::None } => r"(?:[1-9]|1[0-2])".to_string(),
+        Component::Day { padding: Padding::Zero } => r"(?:0[1-9]|[12]\d|3[01])".to_string(),
+        Component::Day { padding: Padding::None } => r"(?:[1-9]|[12]\d|3[01])".to_string(),
+        Component::Hour24 { padding: Padding::Zero } => r"(?:[01]\d|2[0-3])".to_string(),
+        Component::Hour24 { padding: Padding::None } => r"(?:\d|1\d|2[0-3])".to_string(),
+        Component::Hour12 { padding: Padding::Zero } => r"(?:0[1-9]|1[0-2])".to_string(),
+        Component::Hour12 { padding: Padding::None } => r"(?:[1-9]|1[0-2])".to_string(),
+        Component::Minute { padding: Padding::Zero } | Component::Second { padding: Padding::Zero } => {
+            r"[0-5]\d".to_string()
+        }
+        Component::Minute { padding: Padding::None } | Component::Second { padding: Padding::None } => {
+            r"\d{1,2}".to_string()
+        }
+        Component::FractionalSecond { digits } => format!(r"\.\d{{{digits}}}"),
+        Component::WeekdayName => r"[A-Za-z]+".to_string(),
+        Component::IsoWeek { padding: Padding::Zero } => r"(?:0[1-9]|[1-4]\d|5[0-3])".to_string(),
+        Component::IsoWeek { padding: Padding::None } => r"(?:[1-9]|[1-4]\d|5[0-3])".to_string(),
+        Component::ZoneOffset => r"(?:Z|[+-]\d{2}:?\d{2})".to_string(),
+    }
+}
+
+impl CompiledFormat {
+    /// Render this format as an anchored regex pattern suitable for identification: required
+    /// items are concatenated verbatim, optional `[...]` sections become `(?:...)?`.
+    pub fn to_regex_pattern(&self) -> String {
+        let mut pattern = String::from("^");
+        for item in &self.items {
+            match item {
+                Item::Required(component) => pattern.push_str(&component_regex(component)),
+                Item::Optional(components) => {
+                    pattern.push_str("(?:");
+                    for component in components {
+                        pattern.push_str(&component_regex(component));
+                    }
+                    pattern.push_str(")?");
+                }
+            }
+        }
+        pattern.push('$');
+        pattern
+    }
+}
+
+fn component_chrono(component: &Component) -> String {
+    match component {
+        Component::Literal(text) => text.replace('%', "%%"),
+        Component::Year { full: true } => "%Y".to_string(),
+        Component::Year { full: false } => "%y".to_string(),
+        Component::Month { padding: Padding::Zero } => "%m".to_string(),
+        Component::Month { padding: Padding::None } => "%-m".to_string(),
+        Component::Day { padding: Padding::Zero } => "%d".to_string(),
+        Component::Day { padding: Padding::None } => "%-d".to_string(),
+        Component::Hour24 { padding: Padding::Zero } => "%H".to_string(),
+        Component::Hour24 { padding: Padding::None } => "%-H".to_string(),
+        Component::Hour12 { padding: Padding::Zero } => "%I".to_string(),
+        Component::Hour12 { padding: Padding::None } => "%-I".to_string(),
+        Component::Minute { padding: Padding::Zero } => "%M".to_string(),
+        Component::Minute { padding: Padding::None } => "%-M".to_string(),
+        Component::Second { padding: Padding::Zero } => "%S".to_string(),
+        Component::Second { padding: Padding::None } => "%-S".to_string(),
+        // chrono only has fixed-width `%.3f`/`%.6f`/`%.9f`; any other width uses `%.f`.
+        Component::FractionalSecond { digits: digits @ (3 | 6 | 9) } => format!("%.{digits}f"),
+        Component::FractionalSecond { .. } => "%.f".to_string(),
+        Component::WeekdayName => "%a".to_string(),
+        Component::IsoWeek { .. } => "%V".to_string(),
+        Component::ZoneOffset => "%:z".to_string(),
+    }
+}
+
+impl CompiledFormat {
+    /// Render this format as a `chrono` strftime pattern usable for parsing. `[...]` sections
+    /// have no `chrono` equivalent, so their components are emitted as if required; a value
+    /// that relies on the section being skippable won't parse this way.
+    pub fn to_chrono_pattern(&self) -> String {
+        let mut pattern = String::new();
+        for item in &self.items {
+            let components = match item {
+                Item::Required(component) => std::slice::from_ref(component),
+                Item::Optional(components) => components.as_slice(),
+            };
+            for component in components {
+                pattern.push_str(&component_chrono(component));
+            }
+        }
+        pattern
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_format_round_trips_request_example() {
+        // The exact pattern from the request body: a leading literal dot before `SSS` must not
+        // turn into two dots in either the generated regex or the generated chrono pattern.
+        let compiled = compile_format("yyyy-MM-dd'T'HH:mm:ss.SSSXXX").unwrap();
+        let value = "2025-05-19T14:30:15.123+02:00";
+
+        let regex = regex::Regex::new(&compiled.to_regex_pattern()).unwrap();
+        assert!(regex.is_match(value), "pattern {:?} didn't match {value:?}", compiled.to_regex_pattern());
+
+        let chrono_pattern = compiled.to_chrono_pattern();
+        assert!(
+            chrono::DateTime::parse_from_str(value, &chrono_pattern).is_ok(),
+            "chrono pattern {chrono_pattern:?} didn't parse {value:?}"
+        );
+    }
+
+    #[test]
+    fn test_compile_format_optional_section_is_skippable_in_regex() {
+        let compiled = compile_format("HH:mm[:ss]").unwrap();
+        let regex = regex::Regex::new(&compiled.to_regex_pattern()).unwrap();
+
+        assert!(regex.is_match("14:30:15"));
+        assert!(regex.is_match("14:30"));
+    }
+}
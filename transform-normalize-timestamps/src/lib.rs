@@ -1,24 +1,82 @@
-mod time_patterns;
-// use chrono::{DateTime, Utc};
+// These modules' `pub` items (calendar conversions, the format compiler, locale name
+// normalization, RRULE expansion, and timestamp identification/parsing) are part of this
+// crate's public API, not just the SmartModule entry points below, so the modules themselves
+// need to be `pub` for that visibility to actually reach outside the crate.
+pub mod calendars;
+pub mod format_compiler;
+pub mod locale;
+pub mod recurrence;
+pub mod time_patterns;
+
 use fluvio_smartmodule::dataplane::smartmodule::SmartModuleExtraParams;
 use fluvio_smartmodule::{RecordData, Result, SmartModuleRecord, smartmodule};
+use time_patterns::{parse_timestamp, set_default_date_order, set_lenient_mode, DateOrder};
 
+/// Normalize any recognized timestamp in the record value to RFC 3339. Values that don't
+/// match a known format (or whose format has no parser yet) pass through unchanged.
 #[smartmodule(map)]
 pub fn map(record: &SmartModuleRecord) -> Result<(Option<RecordData>, RecordData)> {
     let key = record.key.clone();
 
     let string = std::str::from_utf8(record.value.as_ref())?;
-    let int = string.parse::<i32>()?;
-    let value = (int * 2).to_string();
+    let value = match parse_timestamp(string) {
+        Some(normalized) => normalized.to_rfc3339(),
+        None => string.to_string(),
+    };
 
     Ok((key, value.into()))
 }
 
+/// Hard cap on occurrences emitted per record, independent of the rule's own `COUNT`/`UNTIL`/
+/// `MAX_YEAR` bounds, so a single pathological record can't blow up a pipeline's memory.
+const MAX_OCCURRENCES_PER_RECORD: usize = 10_000;
+
+/// Expand a record's iCalendar `RRULE`/`DTSTART` pair into one output record per occurrence,
+/// each an RFC 3339 instant. Records without a recognizable `DTSTART`/`RRULE` pair, or whose
+/// `DTSTART` doesn't parse as a known timestamp format, pass through unchanged.
+#[smartmodule(array_map)]
+pub fn array_map(record: &SmartModuleRecord) -> Result<Vec<(Option<RecordData>, RecordData)>> {
+    let key = record.key.clone();
+    let string = std::str::from_utf8(record.value.as_ref())?;
+
+    let Some((dtstart_raw, rrule_raw)) = recurrence::parse_record(string) else {
+        return Ok(vec![(key, string.to_string().into())]);
+    };
+    let Some(dtstart) = parse_timestamp(&dtstart_raw) else {
+        return Ok(vec![(key, string.to_string().into())]);
+    };
+    let Some(rule) = recurrence::parse_rrule(&rrule_raw) else {
+        return Ok(vec![(key, string.to_string().into())]);
+    };
+
+    let occurrences = recurrence::Occurrences::new(rule, dtstart)
+        .take(MAX_OCCURRENCES_PER_RECORD)
+        .map(|occurrence| (key.clone(), occurrence.to_rfc3339().into()))
+        .collect();
+
+    Ok(occurrences)
+}
+
+/// Reads the optional `date_order` param (`MDY` or `DMY`) used to resolve genuinely ambiguous
+/// US/EU-style dates, and the optional `lenient` param that, when `true`, retries a value that
+/// doesn't match as-is after sanitizing it (messy RFC 822/1123-style dates, unpadded fields, ...).
+/// `date_order` defaults to `MDY` (any value other than `DMY` also means `MDY`), and `lenient` to
+/// off, when absent.
 #[smartmodule(init)]
-fn init(_params: SmartModuleExtraParams) -> Result<()> {
-    // You can refer to the example SmartModules in Fluvio's GitHub Repository
-    // https://github.com/infinyon/fluvio/tree/master/smartmodule
-    todo!("Provide initialization logic for your SmartModule")
+fn init(params: SmartModuleExtraParams) -> Result<()> {
+    if let Some(order) = params.get("date_order") {
+        let order = match order.to_uppercase().as_str() {
+            "DMY" => DateOrder::Dmy,
+            _ => DateOrder::Mdy,
+        };
+        set_default_date_order(order);
+    }
+
+    if let Some(lenient) = params.get("lenient") {
+        set_lenient_mode(lenient == "true");
+    }
+
+    Ok(())
 }
 
 // fn main() {
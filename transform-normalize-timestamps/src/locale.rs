@@ -0,0 +1,113 @@
+//! Locale-aware recognition of month/weekday names in the textual formats (`RFC_822_1123`,
+//! `ANSI_C_ASCTIME`, `UK_DATETIME`, ...) that otherwise only match English
+//! `[A-Z][a-z]{2}`-style abbreviations.
+//!
+//! Full locale data (via `icu_datetime` or `pure-rust-locales`) is a sizeable dependency for
+//! what most users need here; this ships a small built-in table per supported locale and
+//! normalizes a localized name to its canonical English three-letter abbreviation before
+//! delegating to `identify_timestamp_format`.
+
+/// `(localized full name, localized abbreviation, canonical English abbreviation)`.
+type NameEntry = (&'static str, &'static str, &'static str);
+
+const DE_NAMES: &[NameEntry] = &[
+    ("Januar", "Jan", "Jan"),
+    ("Februar", "Feb", "Feb"),
+    ("März", "Mär", "Mar"),
+    ("April", "Apr", "Apr"),
+    ("Mai", "Mai", "May"),
+    ("Juni", "Jun", "Jun"),
+    ("Juli", "Jul", "Jul"),
+    ("August", "Aug", "Aug"),
+    ("September", "Sep", "Sep"),
+    ("Oktober", "Okt", "Oct"),
+    ("November", "Nov", "Nov"),
+    ("Dezember", "Dez", "Dec"),
+    ("Montag", "Mo", "Mon"),
+    ("Dienstag", "Di", "Tue"),
+    ("Mittwoch", "Mi", "Wed"),
+    ("Donnerstag", "Do", "Thu"),
+    ("Freitag", "Fr", "Fri"),
+    ("Samstag", "Sa", "Sat"),
+    ("Sonntag", "So", "Sun"),
+];
+
+const FR_NAMES: &[NameEntry] = &[
+    ("Janvier", "Janv", "Jan"),
+    ("Février", "Févr", "Feb"),
+    ("Mars", "Mar", "Mar"),
+    ("Avril", "Avr", "Apr"),
+    ("Mai", "Mai", "May"),
+    ("Juin", "Juin", "Jun"),
+    ("Juillet", "Juil", "Jul"),
+    ("Août", "Août", "Aug"),
+    ("Septembre", "Sept", "Sep"),
+    ("Octobre", "Oct", "Oct"),
+    ("Novembre", "Nov", "Nov"),
+    ("Décembre", "Déc", "Dec"),
+    ("Lundi", "Lun", "Mon"),
+    // No distinct 3-letter abbreviation: "Mar" is the common abbreviation for "Mars" (March),
+    // so giving Tuesday the same one would steal "19-mar-2025"-style month tokens. Only the
+    // full name matches here.
+    ("Mardi", "Mardi", "Tue"),
+    ("Mercredi", "Mer", "Wed"),
+    ("Jeudi", "Jeu", "Thu"),
+    ("Vendredi", "Ven", "Fri"),
+    ("Samedi", "Sam", "Sat"),
+    ("Dimanche", "Dim", "Sun"),
+];
+
+fn locale_table(locale: &str) -> Option<&'static [NameEntry]> {
+    match locale.split(['-', '_']).next().unwrap_or(locale) {
+        "de" => Some(DE_NAMES),
+        "fr" => Some(FR_NAMES),
+        _ => None,
+    }
+}
+
+/// Replace any whitespace-separated word in `input` that matches a localized month/weekday name
+/// (full or abbreviated, trailing `.` ignored, case-insensitive) with its canonical English
+/// three-letter abbreviation, rejoining the words with single spaces. Only whole words are
+/// matched: a name joined to other text by other punctuation (`19-Mär-2025`, `Mo,`) is left as
+/// it is. Unsupported locales pass through unchanged.
+pub fn normalize_locale_names(input: &str, locale: &str) -> String {
+    let Some(names) = locale_table(locale) else {
+        return input.to_string();
+    };
+
+    input
+        .split_whitespace()
+        .map(|word| {
+            let trimmed = word.trim_end_matches('.');
+            let lower = trimmed.to_lowercase();
+            names
+                .iter()
+                .find(|(full, abbr, _)| lower == full.to_lowercase() || lower == abbr.to_lowercase())
+                .map(|(_, _, en)| *en)
+                .unwrap_or(word)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_german_month_and_weekday() {
+        assert_eq!(normalize_locale_names("19. Mär 2025", "de"), "19. Mar 2025");
+        assert_eq!(normalize_locale_names("Montag", "de-DE"), "Mon");
+    }
+
+    #[test]
+    fn test_normalize_french_march_abbreviation_not_stolen_by_tuesday() {
+        assert_eq!(normalize_locale_names("19 mar 2025", "fr"), "19 Mar 2025");
+        assert_eq!(normalize_locale_names("Mardi", "fr"), "Tue");
+    }
+
+    #[test]
+    fn test_normalize_unsupported_locale_passes_through() {
+        assert_eq!(normalize_locale_names("19 Jan 2025", "ja"), "19 Jan 2025");
+    }
+}
@@ -0,0 +1,304 @@
+//! Expansion of iCalendar `RRULE` recurrence rules into concrete occurrence timestamps.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+
+/// Hard backstop so a rule with no `COUNT`/`UNTIL` (or one whose `BYDAY`/`BYMONTHDAY`/`BYMONTH`
+/// filters never match) can't loop forever: expansion always stops once it passes this year.
+const MAX_YEAR: i32 = 9999;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A parsed `RRULE` value, e.g. `FREQ=WEEKLY;INTERVAL=2;COUNT=10;BYDAY=MO,WE,FR`.
+#[derive(Clone, Debug)]
+pub struct Rrule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<DateTime<Utc>>,
+    pub by_day: Vec<Weekday>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+}
+
+fn parse_weekday(token: &str) -> Option<Weekday> {
+    match token {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parse an `RRULE` value (the `RRULE:` prefix, if present, is stripped automatically).
+pub fn parse_rrule(value: &str) -> Option<Rrule> {
+    let value = value.trim().trim_start_matches("RRULE:");
+
+    let mut freq = None;
+    let mut interval = 1u32;
+    let mut count = None;
+    let mut until = None;
+    let mut by_day = Vec::new();
+    let mut by_month_day = Vec::new();
+    let mut by_month = Vec::new();
+
+    for part in value.split(';') {
+        let (key, val) = part.split_once('=')?;
+        match key {
+            "FREQ" => {
+                freq = match val {
+                    "DAILY" => Some(Frequency::Daily),
+                    "WEEKLY" => Some(Frequency::Weekly),
+                    "MONTHLY" => Some(Frequency::Monthly),
+                    "YEARLY" => Some(Frequency::Yearly),
+                    _ => None,
+                }
+            }
+            "INTERVAL" => interval = val.parse().ok()?,
+            "COUNT" => count = val.parse().ok(),
+            "UNTIL" => until = crate::time_patterns::parse_timestamp(val),
+            "BYDAY" => by_day = val.split(',').filter_map(parse_weekday).collect(),
+            "BYMONTHDAY" => by_month_day = val.split(',').filter_map(|s| s.parse().ok()).collect(),
+            "BYMONTH" => by_month = val.split(',').filter_map(|s| s.parse().ok()).collect(),
+            _ => {}
+        }
+    }
+
+    Some(Rrule {
+        freq: freq?,
+        interval: interval.max(1),
+        count,
+        until,
+        by_day,
+        by_month_day,
+        by_month,
+    })
+}
+
+/// Find a `DTSTART` and `RRULE` value pair among the `KEY[;...]:VALUE` lines of an iCalendar
+/// record, e.g. `DTSTART:20250101T090000Z` / `RRULE:FREQ=WEEKLY;COUNT=5`.
+pub fn parse_record(input: &str) -> Option<(String, String)> {
+    let mut dtstart = None;
+    let mut rrule = None;
+
+    for line in input.lines() {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        if key.starts_with("DTSTART") {
+            dtstart = Some(value.to_string());
+        } else if key.starts_with("RRULE") {
+            rrule = Some(value.to_string());
+        }
+    }
+
+    Some((dtstart?, rrule?))
+}
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let first_of_next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    };
+    first_of_next
+        .and_then(|d| d.pred_opt())
+        .map(|d| d.day())
+        .unwrap_or(28)
+}
+
+fn add_months(dt: DateTime<Utc>, months: i32) -> DateTime<Utc> {
+    let total = dt.year() * 12 + (dt.month() as i32 - 1) + months;
+    let year = total.div_euclid(12);
+    let month = (total.rem_euclid(12) + 1) as u32;
+    let day = dt.day().min(last_day_of_month(year, month));
+
+    Utc.with_ymd_and_hms(year, month, day, dt.hour(), dt.minute(), dt.second())
+        .single()
+        .unwrap_or(dt)
+}
+
+fn advance(dt: DateTime<Utc>, freq: Frequency, interval: u32) -> DateTime<Utc> {
+    match freq {
+        Frequency::Daily => dt + Duration::days(interval as i64),
+        Frequency::Weekly => dt + Duration::weeks(interval as i64),
+        Frequency::Monthly => add_months(dt, interval as i32),
+        Frequency::Yearly => add_months(dt, interval as i32 * 12),
+    }
+}
+
+/// Greatest day that can ever occur in `month`, across any year (permissive for February: some
+/// years have 29). Only used to rule out a `BYMONTHDAY` that can never occur in a given
+/// `BYMONTH`, not to validate any particular year.
+fn max_day_in_month(month: u32) -> u32 {
+    last_day_of_month(2000, month) // 2000 is a leap year, so this is the permissive bound
+}
+
+/// Whether `rule`'s `BYMONTH`/`BYMONTHDAY` filters can ever be satisfied together. A rule like
+/// `BYMONTH=2;BYMONTHDAY=30` can never produce an occurrence (February never has a 30th) and,
+/// left unchecked, would send `Occurrences::next` walking candidate by candidate all the way to
+/// `MAX_YEAR` before giving up. Negative `BYMONTHDAY` values (days counted from the end of the
+/// month) aren't resolved against a specific month's length here, so they're always treated as
+/// feasible. A rule with no `BYMONTH`, no `BYMONTHDAY`, or neither is always feasible too.
+fn by_filters_are_feasible(rule: &Rrule) -> bool {
+    if rule.by_month.is_empty() || rule.by_month_day.is_empty() {
+        return true;
+    }
+
+    rule.by_month.iter().any(|&month| {
+        rule.by_month_day
+            .iter()
+            .any(|&day| day < 1 || day as u32 <= max_day_in_month(month))
+    })
+}
+
+fn passes_by_filters(rule: &Rrule, candidate: DateTime<Utc>) -> bool {
+    if !rule.by_month.is_empty() && !rule.by_month.contains(&candidate.month()) {
+        return false;
+    }
+    if !rule.by_month_day.is_empty() && !rule.by_month_day.contains(&(candidate.day() as i32)) {
+        return false;
+    }
+    if !rule.by_day.is_empty() && !rule.by_day.contains(&candidate.weekday()) {
+        return false;
+    }
+    true
+}
+
+/// Walks candidate occurrences forward from `DTSTART` by `freq * interval`, applying the
+/// `BYDAY`/`BYMONTHDAY`/`BYMONTH` filters as inclusion tests, and stopping on `COUNT`
+/// occurrences, past `UNTIL`, or past `MAX_YEAR` (whichever comes first).
+pub struct Occurrences {
+    rule: Rrule,
+    cursor: DateTime<Utc>,
+    emitted: u32,
+    done: bool,
+}
+
+impl Occurrences {
+    pub fn new(rule: Rrule, dtstart: DateTime<Utc>) -> Self {
+        let done = !by_filters_are_feasible(&rule);
+        Occurrences {
+            rule,
+            cursor: dtstart,
+            emitted: 0,
+            done,
+        }
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<DateTime<Utc>> {
+        loop {
+            if self.done || self.cursor.year() > MAX_YEAR {
+                return None;
+            }
+            if let Some(count) = self.rule.count {
+                if self.emitted >= count {
+                    self.done = true;
+                    return None;
+                }
+            }
+            if let Some(until) = self.rule.until {
+                if self.cursor > until {
+                    self.done = true;
+                    return None;
+                }
+            }
+
+            let candidate = self.cursor;
+            self.cursor = advance(self.cursor, self.rule.freq, self.rule.interval);
+
+            if passes_by_filters(&self.rule, candidate) {
+                self.emitted += 1;
+                return Some(candidate);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn test_daily_count_stops_at_count() {
+        let rule = parse_rrule("FREQ=DAILY;COUNT=3").unwrap();
+        let occurrences: Vec<_> = Occurrences::new(rule, dt(2025, 1, 1, 9, 0, 0)).collect();
+        assert_eq!(
+            occurrences,
+            vec![dt(2025, 1, 1, 9, 0, 0), dt(2025, 1, 2, 9, 0, 0), dt(2025, 1, 3, 9, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_daily_until_stops_after_the_bound() {
+        let rule = parse_rrule("FREQ=DAILY;UNTIL=2025-01-03T09:00:00Z").unwrap();
+        let occurrences: Vec<_> = Occurrences::new(rule, dt(2025, 1, 1, 9, 0, 0)).collect();
+        assert_eq!(
+            occurrences,
+            vec![dt(2025, 1, 1, 9, 0, 0), dt(2025, 1, 2, 9, 0, 0), dt(2025, 1, 3, 9, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_daily_byday_filter_keeps_only_matching_weekdays() {
+        // 2025-01-01 is a Wednesday; BYDAY=MO,WE,FR should keep Jan 1 (Wed), skip Jan 2 (Thu),
+        // keep Jan 3 (Fri), skip the weekend, and keep Jan 6 (Mon).
+        let rule = parse_rrule("FREQ=DAILY;BYDAY=MO,WE,FR;COUNT=3").unwrap();
+        let occurrences: Vec<_> = Occurrences::new(rule, dt(2025, 1, 1, 9, 0, 0)).collect();
+        assert_eq!(
+            occurrences,
+            vec![dt(2025, 1, 1, 9, 0, 0), dt(2025, 1, 3, 9, 0, 0), dt(2025, 1, 6, 9, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_unsatisfiable_bymonth_bymonthday_short_circuits_immediately() {
+        // February never has a 30th: without the feasibility check this would walk the cursor
+        // year by year all the way to `MAX_YEAR` before giving up.
+        let rule = parse_rrule("FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30").unwrap();
+        assert_eq!(Occurrences::new(rule, dt(2025, 1, 1, 0, 0, 0)).next(), None);
+    }
+
+    #[test]
+    fn test_satisfiable_bymonth_bymonthday_still_expands() {
+        // Feb 29 is possible in a leap year, so this rule is feasible even though not every
+        // year satisfies it; starting on a leap-year Jan 29 should land on that Feb 29.
+        let rule = parse_rrule("FREQ=MONTHLY;BYMONTH=2;BYMONTHDAY=29;COUNT=1").unwrap();
+        let occurrences: Vec<_> = Occurrences::new(rule, dt(2024, 1, 29, 0, 0, 0)).collect();
+        assert_eq!(occurrences, vec![dt(2024, 2, 29, 0, 0, 0)]);
+    }
+
+    #[test]
+    fn test_record_dtstart_and_rrule_parse_through_array_map_string_path() {
+        // Exercises the same string-parsing path `array_map` drives: `parse_record` to split the
+        // raw iCalendar lines, then `crate::time_patterns::parse_timestamp` on the raw `DTSTART`
+        // value (rather than building a `DateTime<Utc>` directly via the `dt()` helper above).
+        let record = "DTSTART:20250101T090000Z\nRRULE:FREQ=DAILY;COUNT=2";
+        let (dtstart_raw, rrule_raw) = parse_record(record).unwrap();
+        let dtstart = crate::time_patterns::parse_timestamp(&dtstart_raw).unwrap();
+        let rule = parse_rrule(&rrule_raw).unwrap();
+
+        let occurrences: Vec<_> = Occurrences::new(rule, dtstart).collect();
+        assert_eq!(
+            occurrences,
+            vec![dt(2025, 1, 1, 9, 0, 0), dt(2025, 1, 2, 9, 0, 0)]
+        );
+    }
+}
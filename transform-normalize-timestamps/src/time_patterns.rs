@@ -1,4 +1,6 @@
 use lazy_static::lazy_static;
+use regex::RegexSet;
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 // A map of timestamp format names to their corresponding Rust regex patterns.
@@ -16,6 +18,7 @@ lazy_static! {
         map.insert("ISO_DATETIME_MS_UTC", r"^\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])T([01]\d|2[0-3]):[0-5]\d:[0-5]\d\.\d{1,9}(?:Z|UTC)$");
         map.insert("ISO_DATE_BASIC", r"^\d{4}(0[1-9]|1[0-2])(0[1-9]|[12]\d|3[01])$");
         map.insert("ISO_DATETIME_BASIC", r"^\d{4}(0[1-9]|1[0-2])(0[1-9]|[12]\d|3[01])T([01]\d|2[0-3])[0-5]\d[0-5]\d$");
+        map.insert("ISO_DATETIME_BASIC_UTC", r"^\d{4}(0[1-9]|1[0-2])(0[1-9]|[12]\d|3[01])T([01]\d|2[0-3])[0-5]\d[0-5]\dZ$");
         map.insert("ISO_ORDINAL_DATE", r"^\d{4}-(00[1-9]|0[1-9]\d|[1-2]\d\d|3[0-5]\d|36[0-6])$");
         map.insert("ISO_WEEK_DATE", r"^\d{4}-W(0[1-9]|[1-4]\d|5[0-3])-[1-7]$");
 
@@ -112,23 +115,740 @@ lazy_static! {
 
         map
     };
+
+    /// Names of `TIMESTAMP_FORMATS`, in the exact order used to build `FORMAT_SET` so that
+    /// a match index from the set can be mapped straight back to its format name. Sorted so
+    /// this order (and thus which "first match wins" downstream) is stable across process
+    /// restarts instead of following `HashMap`'s randomized per-process iteration order.
+    static ref FORMAT_NAMES: Vec<&'static str> = {
+        let mut names: Vec<&'static str> = TIMESTAMP_FORMATS.keys().copied().collect();
+        names.sort_unstable();
+        names
+    };
+
+    /// All patterns compiled once into a single multi-pattern `RegexSet`. Building this is the
+    /// expensive part, so it happens a single time per process instead of per call.
+    static ref FORMAT_SET: RegexSet = RegexSet::new(
+        FORMAT_NAMES.iter().map(|name| TIMESTAMP_FORMATS[name])
+    )
+    .expect("every pattern in TIMESTAMP_FORMATS must compile");
+}
+
+/// Match `timestamp` against all known formats in a single scan over the precompiled
+/// `FORMAT_SET`, without any sanitization.
+fn identify_raw(timestamp: &str) -> Vec<&'static str> {
+    let mut matches: Vec<&'static str> = FORMAT_SET
+        .matches(timestamp)
+        .into_iter()
+        .map(|idx| FORMAT_NAMES[idx])
+        .collect();
+
+    for custom in CUSTOM_FORMATS.read().unwrap().iter() {
+        if custom.regex.is_match(timestamp) {
+            matches.push(custom.name);
+        }
+    }
+
+    matches
+}
+
+/// A user-registered format compiled via `register_format`, alongside the regex and
+/// `chrono` pattern derived from it.
+struct CustomFormat {
+    name: &'static str,
+    regex: regex::Regex,
+    chrono_pattern: String,
+}
+
+lazy_static! {
+    static ref CUSTOM_FORMATS: std::sync::RwLock<Vec<CustomFormat>> = std::sync::RwLock::new(Vec::new());
+}
+
+/// Compile a Java/strftime-style pattern string (see `format_compiler::compile_format`) and
+/// register it under `name` so it participates in both `identify_timestamp_format` and
+/// `parse_timestamp`/`normalize_timestamp`, alongside the built-in `TIMESTAMP_FORMATS`.
+///
+/// `[...]` optional sections are honored for identification but, since `chrono` has no
+/// optional-section syntax, are treated as required when parsing the matched value.
+pub fn register_format(
+    name: &'static str,
+    pattern: &str,
+) -> Result<(), crate::format_compiler::FormatError> {
+    use crate::format_compiler::{compile_format, FormatError};
+
+    let compiled = compile_format(pattern)?;
+    let regex = regex::Regex::new(&compiled.to_regex_pattern())
+        .map_err(|_| FormatError::InvalidGeneratedRegex)?;
+    let chrono_pattern = compiled.to_chrono_pattern();
+
+    CUSTOM_FORMATS.write().unwrap().push(CustomFormat {
+        name,
+        regex,
+        chrono_pattern,
+    });
+    Ok(())
+}
+
+lazy_static! {
+    static ref LENIENT_MODE: std::sync::RwLock<bool> = std::sync::RwLock::new(false);
+}
+
+/// Enable or disable lenient matching, i.e. falling back to `lenient_fallback` (`sanitize_rfc822`,
+/// then `sanitize`) when a value doesn't match any format as-is. Intended to be set once from `init` via a `lenient` param.
+pub fn set_lenient_mode(enabled: bool) {
+    *LENIENT_MODE.write().unwrap() = enabled;
+}
+
+fn is_lenient_mode() -> bool {
+    *LENIENT_MODE.read().unwrap()
+}
+
+/// Try lenient mode's two sanitizers in order: `sanitize_rfc822` first, since it's the only one
+/// that canonicalizes a trailing zone token (`UT`, numeric offsets, ...) to `GMT`; then the more
+/// general `sanitize`, which catches messy values that aren't RFC 822/1123-shaped at all (e.g. an
+/// unpadded `SQL_TIMESTAMP`). Returns the sanitized string alongside whatever it identified, or
+/// `None` if neither fallback matches anything.
+fn lenient_fallback(input: &str) -> Option<(String, Vec<&'static str>)> {
+    let rfc822 = sanitize_rfc822(input);
+    let rfc822_candidates = identify_raw(&rfc822);
+    if !rfc822_candidates.is_empty() {
+        return Some((rfc822, rfc822_candidates));
+    }
+
+    let general = sanitize(input).into_owned();
+    let general_candidates = identify_raw(&general);
+    if !general_candidates.is_empty() {
+        return Some((general, general_candidates));
+    }
+
+    None
 }
 
-/// Example usage to match a timestamp against available patterns
+/// Match a timestamp against all known formats. When lenient mode is enabled and the value
+/// doesn't match as-is, retries against `lenient_fallback` so messy mail/HTTP style dates
+/// (unpadded days, lowercase months, stray whitespace, ...) and other malformed-but-recoverable
+/// values are still recognized.
 pub fn identify_timestamp_format(timestamp: &str) -> Vec<&'static str> {
-    use regex::Regex;
+    let direct = identify_raw(timestamp);
+    if !direct.is_empty() || !is_lenient_mode() {
+        return direct;
+    }
+
+    lenient_fallback(timestamp)
+        .map(|(_, candidates)| candidates)
+        .unwrap_or_default()
+}
+
+/// Like `identify_timestamp_format`, but first normalizes any localized month/weekday name in
+/// `timestamp` (per `locale`, e.g. `"de"` or `"fr"`) to its English abbreviation, so textual
+/// formats whose names stand as separate words (e.g. `ANSI_C_ASCTIME`, or `RFC_822_1123`'s
+/// month) recognize non-English logs. A name joined to other text by punctuation, such as in
+/// `UK_DATETIME`'s `19-Mär-2025` or `RFC_822_1123`'s `Mo,` weekday, is not translated.
+pub fn identify_timestamp_format_locale(timestamp: &str, locale: &str) -> Vec<&'static str> {
+    identify_timestamp_format(&crate::locale::normalize_locale_names(timestamp, locale))
+}
+
+const RFC822_WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const RFC822_MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Match `token` (any case, any length) against the three-letter abbreviations in `table` by
+/// prefix, returning the canonical title-cased abbreviation.
+fn canonical_abbr(token: &str, table: &[&'static str]) -> Option<&'static str> {
+    let lower = token.to_ascii_lowercase();
+    table
+        .iter()
+        .copied()
+        .find(|abbr| lower.starts_with(&abbr.to_ascii_lowercase()))
+}
 
-    let mut matches = Vec::new();
+lazy_static! {
+    /// A loose RFC 822/1123 shape: unpadded day, any-case weekday/month names (optionally with
+    /// trailing punctuation), and a trailing zone token of any form.
+    static ref LENIENT_RFC822: regex::Regex = regex::Regex::new(
+        r"(?i)^([a-z]+),?\s+(\d{1,2})\s+([a-z]+)\.?\s+(\d{4})\s+(\d{1,2}):(\d{2}):(\d{2})\s+(\S+)$"
+    )
+    .expect("LENIENT_RFC822 pattern must compile");
+}
+
+/// Normalize the common ways real-world RFC 822/1123 dates deviate from the strict
+/// `RFC_822_1123` pattern: collapses repeated whitespace, zero-pads the day, title-cases and
+/// truncates the weekday/month to three letters, and canonicalizes the zone token to `GMT`.
+/// Returns the input with whitespace collapsed, unchanged, if it doesn't look RFC 822-shaped.
+pub fn sanitize_rfc822(input: &str) -> String {
+    let collapsed = input.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let Some(caps) = LENIENT_RFC822.captures(&collapsed) else {
+        return collapsed;
+    };
+
+    let (Some(weekday), Some(month)) = (
+        canonical_abbr(&caps[1], &RFC822_WEEKDAYS),
+        canonical_abbr(&caps[3], &RFC822_MONTHS),
+    ) else {
+        return collapsed;
+    };
+    let (Ok(day), Ok(hour)) = (caps[2].parse::<u32>(), caps[5].parse::<u32>()) else {
+        return collapsed;
+    };
 
-    for (name, pattern) in TIMESTAMP_FORMATS.iter() {
-        if let Ok(regex) = Regex::new(pattern) {
-            if regex.is_match(timestamp) {
-                matches.push(*name);
+    format!(
+        "{}, {:02} {} {} {:02}:{}:{} GMT",
+        weekday, day, month, &caps[4], hour, &caps[6], &caps[7]
+    )
+}
+
+/// Zero-pad every standalone run of ASCII digits that is exactly one digit long (a day, hour,
+/// minute, or second field written without its leading zero) to two digits. Longer runs (years,
+/// already-padded fields) pass through unchanged.
+fn pad_numeric_fields(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i - start == 1 {
+                out.push('0');
             }
+            out.extend(&chars[start..i]);
+        } else {
+            out.push(chars[i]);
+            i += 1;
         }
     }
 
-    matches
+    out
+}
+
+/// Title-case every standalone run of exactly three ASCII letters that matches a month or
+/// weekday abbreviation (any case) to its canonical form. Longer/shorter runs, and runs that
+/// don't match either table, pass through unchanged.
+fn title_case_names(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_ascii_alphabetic() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_alphabetic() {
+                i += 1;
+            }
+            let run: String = chars[start..i].iter().collect();
+            let canonical = if run.len() == 3 {
+                canonical_abbr(&run, &RFC822_MONTHS).or_else(|| canonical_abbr(&run, &RFC822_WEEKDAYS))
+            } else {
+                None
+            };
+            out.push_str(canonical.unwrap_or(&run));
+        } else {
+            out.push(chars[i]);
+            i += 1;
+        }
+    }
+
+    out
+}
+
+/// Drop trailing space-separated words that don't look like part of a timestamp: a word counts
+/// as date-like if, after trimming its surrounding punctuation, it contains a digit, is a
+/// three-letter month/weekday abbreviation, or is a common zone/meridiem marker (`AM`, `PM`,
+/// `UTC`, `GMT`, `Z`). Stops at the first date-like word scanning from the end, so `"2025-05-19
+/// (approx)"` loses `"(approx)"` but keeps everything before it.
+fn strip_trailing_noise(input: &str) -> String {
+    let mut words: Vec<&str> = input.split(' ').collect();
+
+    while words.len() > 1 {
+        let core = words[words.len() - 1].trim_matches(|c: char| !c.is_ascii_alphanumeric());
+        let date_like = core.chars().any(|c| c.is_ascii_digit())
+            || (core.len() == 3
+                && (canonical_abbr(core, &RFC822_MONTHS).is_some()
+                    || canonical_abbr(core, &RFC822_WEEKDAYS).is_some()))
+            || matches!(core.to_ascii_uppercase().as_str(), "AM" | "PM" | "UTC" | "GMT" | "Z");
+
+        if date_like {
+            break;
+        }
+        words.pop();
+    }
+
+    words.join(" ")
+}
+
+/// General-purpose cleanup for a malformed timestamp, unlike `sanitize_rfc822` which only
+/// targets the RFC 822/1123 shape: collapses repeated whitespace, zero-pads single-digit
+/// day/hour/minute/second fields, title-cases three-letter month/weekday abbreviations, and
+/// drops trailing words that aren't part of the timestamp. Returns `input` unchanged (borrowed)
+/// if none of these apply.
+pub fn sanitize(input: &str) -> Cow<'_, str> {
+    let collapsed = input.split_whitespace().collect::<Vec<_>>().join(" ");
+    let trimmed = strip_trailing_noise(&collapsed);
+    let cleaned = title_case_names(&pad_numeric_fields(&trimmed));
+
+    if cleaned == input {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(cleaned)
+    }
+}
+
+/// Like `identify_timestamp_format`, but without touching the process-wide `LENIENT_MODE` flag:
+/// tries `input` as-is, falling back to `sanitize(input)` only when that yields no match. Use
+/// this when a single call site needs leniency rather than every call in the process.
+pub fn identify_timestamp_format_lenient(input: &str) -> Vec<&'static str> {
+    let direct = identify_raw(input);
+    if !direct.is_empty() {
+        return direct;
+    }
+
+    identify_raw(&sanitize(input))
+}
+
+/// How to turn a value that matched a given format name into a UTC instant.
+enum ParseStrategy {
+    /// A calendar value with no zone info; parsed with this `chrono` strftime pattern and
+    /// assumed to already be UTC.
+    NaiveUtc(&'static str),
+    /// A calendar value with an explicit offset or `Z`, parsed as RFC 3339.
+    Rfc3339,
+    /// An RFC 2822/822/1123-style value (`Mon, 19 May 2025 14:30:15 GMT`).
+    Rfc2822,
+    /// A decimal UNIX epoch value at the given sub-second scale (units per second).
+    Epoch { units_per_second: i64 },
+}
+
+lazy_static! {
+    /// Maps a subset of `TIMESTAMP_FORMATS` names to the strategy used to parse them into a
+    /// UTC instant. Formats that need more than a `chrono` strftime pattern to interpret
+    /// (non-Gregorian calendars, timezone-name lookups, ...) are deliberately left out here.
+    static ref PARSE_STRATEGIES: HashMap<&'static str, ParseStrategy> = {
+        let mut map: HashMap<&'static str, ParseStrategy> = HashMap::new();
+
+        map.insert("ISO_DATE", ParseStrategy::NaiveUtc("%Y-%m-%d"));
+        map.insert("ISO_DATETIME", ParseStrategy::NaiveUtc("%Y-%m-%dT%H:%M:%S"));
+        map.insert("ISO_DATETIME_UTC", ParseStrategy::NaiveUtc("%Y-%m-%dT%H:%M:%SZ"));
+        map.insert("ISO_DATETIME_MS", ParseStrategy::NaiveUtc("%Y-%m-%dT%H:%M:%S%.f"));
+        map.insert("ISO_DATETIME_MS_UTC", ParseStrategy::NaiveUtc("%Y-%m-%dT%H:%M:%S%.fZ"));
+        map.insert("ISO_DATE_BASIC", ParseStrategy::NaiveUtc("%Y%m%d"));
+        map.insert("ISO_DATETIME_BASIC", ParseStrategy::NaiveUtc("%Y%m%dT%H%M%S"));
+        map.insert("ISO_DATETIME_BASIC_UTC", ParseStrategy::NaiveUtc("%Y%m%dT%H%M%SZ"));
+        map.insert("DB2_TIMESTAMP", ParseStrategy::NaiveUtc("%Y-%m-%d-%H.%M.%S%.f"));
+        map.insert("ISO_DATETIME_TZ", ParseStrategy::Rfc3339);
+        map.insert("RFC_3339", ParseStrategy::Rfc3339);
+        map.insert("W3C_DTF", ParseStrategy::Rfc3339);
+        map.insert("ISO_TZ_OFFSET", ParseStrategy::Rfc3339);
+        map.insert("ZULU_INDICATOR", ParseStrategy::Rfc3339);
+
+        map.insert("RFC_822_1123", ParseStrategy::Rfc2822);
+
+        map.insert("SQL_TIMESTAMP", ParseStrategy::NaiveUtc("%Y-%m-%d %H:%M:%S"));
+        map.insert("SQL_TIMESTAMP_MS", ParseStrategy::NaiveUtc("%Y-%m-%d %H:%M:%S%.f"));
+        map.insert("EXIF_DATETIME", ParseStrategy::NaiveUtc("%Y:%m:%d %H:%M:%S"));
+        map.insert("MSSQL_TIMESTAMP", ParseStrategy::NaiveUtc("%Y%m%d %H:%M:%S"));
+
+        map.insert("US_DATETIME", ParseStrategy::NaiveUtc("%m/%d/%Y %H:%M:%S"));
+        map.insert("EU_DATETIME", ParseStrategy::NaiveUtc("%d/%m/%Y %H:%M:%S"));
+        map.insert("ASIAN_DATETIME", ParseStrategy::NaiveUtc("%Y/%m/%d %H:%M:%S"));
+        map.insert("GERMAN_DATETIME", ParseStrategy::NaiveUtc("%d.%m.%Y %H:%M:%S"));
+
+        map.insert("UNIX_SECONDS", ParseStrategy::Epoch { units_per_second: 1 });
+        map.insert("TAGGED_UNIX", ParseStrategy::Epoch { units_per_second: 1 });
+        map.insert("UNIX_MILLISECONDS", ParseStrategy::Epoch { units_per_second: 1_000 });
+        map.insert("UNIX_MICROSECONDS", ParseStrategy::Epoch { units_per_second: 1_000_000 });
+        map.insert("UNIX_NANOSECONDS", ParseStrategy::Epoch { units_per_second: 1_000_000_000 });
+
+        map
+    };
+}
+
+/// Parse a value matching `name` using its registered `ParseStrategy`, preserving whatever
+/// offset the source value carried (UTC for the offset-less strategies).
+fn parse_with_strategy_offset(
+    name: &str,
+    input: &str,
+) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+
+    let to_fixed = |dt: DateTime<Utc>| dt.with_timezone(&FixedOffset::east_opt(0).unwrap());
+
+    match PARSE_STRATEGIES.get(name)? {
+        ParseStrategy::NaiveUtc(fmt) => {
+            let naive = NaiveDateTime::parse_from_str(input, fmt).ok()?;
+            Some(to_fixed(Utc.from_utc_datetime(&naive)))
+        }
+        ParseStrategy::Rfc3339 => DateTime::parse_from_rfc3339(input).ok(),
+        ParseStrategy::Rfc2822 => DateTime::parse_from_rfc2822(input).ok(),
+        ParseStrategy::Epoch { units_per_second } => {
+            let digits = input.trim_start_matches('@');
+            let value: i64 = digits.parse().ok()?;
+            let nanos_per_unit = 1_000_000_000 / units_per_second;
+            Utc.timestamp_opt(
+                value / units_per_second,
+                ((value % units_per_second) * nanos_per_unit) as u32,
+            )
+            .single()
+            .map(to_fixed)
+        }
+    }
+}
+
+/// Parse a value matching `name` using its registered `ParseStrategy`, if any.
+fn parse_with_strategy(name: &str, input: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    parse_with_strategy_offset(name, input).map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+/// Parse a value matched against a `register_format`-registered name using its derived
+/// `chrono` pattern, preserving a zone offset when the pattern captured one.
+fn parse_custom_format_offset(name: &str, input: &str) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+
+    let formats = CUSTOM_FORMATS.read().unwrap();
+    let custom = formats.iter().find(|f| f.name == name)?;
+
+    if let Ok(dt) = DateTime::parse_from_str(input, &custom.chrono_pattern) {
+        return Some(dt);
+    }
+    let naive = NaiveDateTime::parse_from_str(input, &custom.chrono_pattern).ok()?;
+    Some(Utc.from_utc_datetime(&naive).with_timezone(&FixedOffset::east_opt(0).unwrap()))
+}
+
+fn parse_custom_format(name: &str, input: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    parse_custom_format_offset(name, input).map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+fn parse_candidates(candidates: Vec<&'static str>, input: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    resolve_ambiguous(candidates, input).into_iter().find_map(|name| {
+        parse_with_strategy(name, input)
+            .or_else(|| parse_calendar_format(name, input))
+            .or_else(|| parse_custom_format(name, input))
+    })
+}
+
+/// Identify the format of `input` and parse it into a UTC instant, or `None` if it doesn't
+/// match a known format or the matched format has no registered parse strategy yet. When
+/// lenient mode is enabled and `input` doesn't match (or parse) as-is, retries once against
+/// `lenient_fallback(input)`.
+pub fn parse_timestamp(input: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Some(dt) = parse_candidates(identify_raw(input), input) {
+        return Some(dt);
+    }
+
+    if is_lenient_mode() {
+        let (sanitized, candidates) = lenient_fallback(input)?;
+        return parse_candidates(candidates, &sanitized);
+    }
+
+    None
+}
+
+/// Parse a value matched against one of the non-Gregorian calendar formats via
+/// `calendars::to_gregorian`, anchoring the result at UTC midnight on that date.
+fn parse_calendar_format(name: &str, input: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    use chrono::TimeZone;
+
+    let calendar = crate::calendars::calendar_kind_for_format(name)?;
+    let date = crate::calendars::to_gregorian(input, calendar)?;
+    let naive = date.and_hms_opt(0, 0, 0)?;
+    Some(chrono::Utc.from_utc_datetime(&naive))
+}
+
+/// A timestamp normalized to a UTC instant at nanosecond precision, alongside the format that
+/// was detected and the timezone offset (in seconds east of UTC) the original value carried.
+/// The offset is always `0` for formats that have no zone of their own (epoch values, naive
+/// calendar timestamps, non-Gregorian calendars).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizedTimestamp {
+    pub epoch_nanos: i64,
+    pub format: &'static str,
+    pub offset_seconds: i32,
+}
+
+fn normalize_candidates(candidates: Vec<&'static str>, input: &str) -> Option<NormalizedTimestamp> {
+    for name in resolve_ambiguous(candidates, input) {
+        if let Some(dt) = parse_with_strategy_offset(name, input) {
+            return Some(NormalizedTimestamp {
+                epoch_nanos: dt.with_timezone(&chrono::Utc).timestamp_nanos_opt()?,
+                format: name,
+                offset_seconds: dt.offset().local_minus_utc(),
+            });
+        }
+        if let Some(utc) = parse_calendar_format(name, input) {
+            return Some(NormalizedTimestamp {
+                epoch_nanos: utc.timestamp_nanos_opt()?,
+                format: name,
+                offset_seconds: 0,
+            });
+        }
+        if let Some(dt) = parse_custom_format_offset(name, input) {
+            return Some(NormalizedTimestamp {
+                epoch_nanos: dt.with_timezone(&chrono::Utc).timestamp_nanos_opt()?,
+                format: name,
+                offset_seconds: dt.offset().local_minus_utc(),
+            });
+        }
+    }
+    None
+}
+
+/// Like `parse_timestamp`, but returns the detected format name and original timezone offset
+/// alongside a nanosecond-precision UTC epoch value instead of just the instant itself.
+pub fn normalize_timestamp(input: &str) -> Option<NormalizedTimestamp> {
+    if let Some(result) = normalize_candidates(identify_raw(input), input) {
+        return Some(result);
+    }
+
+    if is_lenient_mode() {
+        let (sanitized, candidates) = lenient_fallback(input)?;
+        return normalize_candidates(candidates, &sanitized);
+    }
+
+    None
+}
+
+/// The day/month order to assume when a date is genuinely ambiguous (both leading fields `<=12`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DateOrder {
+    /// Month-day-year (US convention).
+    #[default]
+    Mdy,
+    /// Day-month-year (EU/German convention).
+    Dmy,
+}
+
+lazy_static! {
+    static ref DEFAULT_DATE_ORDER: std::sync::RwLock<DateOrder> =
+        std::sync::RwLock::new(DateOrder::default());
+}
+
+/// Set the fallback order `disambiguate_timestamp` uses when a date is genuinely ambiguous.
+/// Intended to be called once from `init` with the SmartModule's `date_order` param.
+pub fn set_default_date_order(order: DateOrder) {
+    *DEFAULT_DATE_ORDER.write().unwrap() = order;
+}
+
+/// Format name pairs where the same value matches both a month-day-year and day-month-year
+/// reading, e.g. `05/07/2025` as either 7 May or 5 July. Shared by `disambiguate_timestamp`,
+/// `resolve_ambiguous`, and `validated_confidence` so they agree on what counts as ambiguous.
+const AMBIGUOUS_PAIRS: [(&str, &str); 2] = [
+    ("US_DATETIME", "EU_DATETIME"),
+    ("SHORT_US_DATETIME", "SHORT_EU_DATETIME"),
+];
+
+/// Extract the two leading numeric fields of `input` (e.g. the `05` and `19` in `05/19/2025 ...`).
+fn leading_numeric_fields(input: &str) -> Option<(u32, u32)> {
+    let mut fields = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty());
+    let first = fields.next()?.parse().ok()?;
+    let second = fields.next()?.parse().ok()?;
+    Some((first, second))
+}
+
+/// When `identify_timestamp_format` returns more than one day/month-ambiguous calendar format
+/// for the same value, pick the most likely one: if the first field can only be a day (`>12`),
+/// prefer the day-first variant; if the second field can only be a day, prefer month-first;
+/// if both are `<=12` the value is genuinely ambiguous, so fall back to `DEFAULT_DATE_ORDER`.
+/// Returns `None` when the fields rule out every remaining candidate, when they can't be read,
+/// or when several formats match but no two of them form one of the `AMBIGUOUS_PAIRS`.
+pub fn disambiguate_timestamp(input: &str) -> Option<&'static str> {
+    let candidates = identify_timestamp_format(input);
+    if candidates.len() <= 1 {
+        return candidates.into_iter().next();
+    }
+
+    let (mdy_name, dmy_name) = AMBIGUOUS_PAIRS
+        .iter()
+        .find(|(mdy, dmy)| candidates.contains(mdy) && candidates.contains(dmy))?;
+
+    let (first, second) = leading_numeric_fields(input)?;
+    let order = match (first > 12, second > 12) {
+        (true, true) => return None,
+        (true, false) => DateOrder::Dmy,
+        (false, true) => DateOrder::Mdy,
+        (false, false) => *DEFAULT_DATE_ORDER.read().unwrap(),
+    };
+
+    Some(match order {
+        DateOrder::Mdy => *mdy_name,
+        DateOrder::Dmy => *dmy_name,
+    })
+}
+
+/// Given a candidate list that may contain both halves of an `AMBIGUOUS_PAIRS` entry, drop the
+/// half that `DEFAULT_DATE_ORDER` (or the numeric fields themselves, when they rule one side
+/// out) says isn't the real reading, so `parse_candidates`/`normalize_candidates` don't pick
+/// whichever half happens to come first instead of honoring the configured date order. Leaves
+/// `candidates` untouched when no ambiguous pair is present or the fields can't be read.
+fn resolve_ambiguous(candidates: Vec<&'static str>, input: &str) -> Vec<&'static str> {
+    let Some((mdy_name, dmy_name)) = AMBIGUOUS_PAIRS
+        .iter()
+        .find(|(mdy, dmy)| candidates.contains(mdy) && candidates.contains(dmy))
+    else {
+        return candidates;
+    };
+
+    let Some((first, second)) = leading_numeric_fields(input) else {
+        return candidates;
+    };
+
+    let chosen = match (first > 12, second > 12) {
+        (true, true) => None,
+        (true, false) => Some(*dmy_name),
+        (false, true) => Some(*mdy_name),
+        (false, false) => Some(match *DEFAULT_DATE_ORDER.read().unwrap() {
+            DateOrder::Mdy => *mdy_name,
+            DateOrder::Dmy => *dmy_name,
+        }),
+    };
+
+    candidates
+        .into_iter()
+        .filter(|name| {
+            if *name != *mdy_name && *name != *dmy_name {
+                return true;
+            }
+            chosen == Some(*name)
+        })
+        .collect()
+}
+
+/// Greatest valid day-of-month for `month` (1-12), taken permissively for February (29) since
+/// without a parsed year we can't tell whether it's a leap year; this still rejects what's
+/// impossible in every year (`30`, `31`).
+fn max_day_for_month(month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => 29,
+        _ => 0,
+    }
+}
+
+lazy_static! {
+    /// Leading `(day, month name)` fields for the handful of `TIMESTAMP_FORMATS` entries that
+    /// spell the month out as a name rather than a number and have no `ParseStrategy` entry of
+    /// their own, so `validated_confidence` can still catch an impossible day like `30 Feb`.
+    static ref DAY_MONTH_NAME_FIELDS: HashMap<&'static str, regex::Regex> = {
+        let mut map = HashMap::new();
+        map.insert("ORACLE_TIMESTAMP", regex::Regex::new(r"^(\d{1,2})-([A-Za-z]{3})-").unwrap());
+        map.insert("SAS_DATETIME", regex::Regex::new(r"^(\d{1,2})([A-Za-z]{3})\d{4}:").unwrap());
+        map.insert("AVIATION_METAR", regex::Regex::new(r"^(\d{2})\d{4}Z ([A-Za-z]{3}) ").unwrap());
+        map
+    };
+}
+
+/// Extract the leading `(day, month)` pair from `input` for one of `DAY_MONTH_NAME_FIELDS`'
+/// formats, resolving the month name via the same case-insensitive table `sanitize_rfc822` uses.
+fn day_month_fields(name: &str, input: &str) -> Option<(u32, u32)> {
+    let caps = DAY_MONTH_NAME_FIELDS.get(name)?.captures(input)?;
+    let day: u32 = caps[1].parse().ok()?;
+    let month_name = canonical_abbr(&caps[2], &RFC822_MONTHS)?;
+    let month = RFC822_MONTHS.iter().position(|m| *m == month_name)? as u32 + 1;
+    Some((day, month))
+}
+
+/// Score a single candidate format against `input`, or `None` if its fields are impossible.
+/// Formats with a registered `ParseStrategy` are validated for free: `chrono` (or the epoch
+/// range check) already rejects invalid months/days/hours/out-of-range epochs. A few more
+/// formats without one (`DAY_MONTH_NAME_FIELDS`) still get their day checked against their
+/// month by hand, since that's the specific invariant `chrono` would otherwise have caught.
+/// Everything else falls through unvalidated: pure time-of-day formats, ordinal/Julian day
+/// numbers and legacy timecodes, and also formats whose day/month fields go unchecked here
+/// (e.g. `UK_DATETIME`, `SHORT_US_DATETIME`, the non-Gregorian calendars). The remaining ambiguity `chrono` can't resolve on its own is the day/month
+/// order of `AMBIGUOUS_PAIRS`, scored the same way `disambiguate_timestamp` does.
+fn validated_confidence(name: &'static str, input: &str) -> Option<f32> {
+    if PARSE_STRATEGIES.contains_key(name) && parse_with_strategy(name, input).is_none() {
+        return None;
+    }
+
+    if let Some((day, month)) = day_month_fields(name, input) {
+        if day < 1 || day > max_day_for_month(month) {
+            return None;
+        }
+    }
+
+    let Some((mdy_name, dmy_name)) = AMBIGUOUS_PAIRS
+        .iter()
+        .find(|(mdy, dmy)| *mdy == name || *dmy == name)
+    else {
+        return Some(1.0);
+    };
+
+    let (first, second) = leading_numeric_fields(input)?;
+    match (first > 12, second > 12) {
+        (true, true) => None,
+        (true, false) => (name == *dmy_name).then_some(1.0),
+        (false, true) => (name == *mdy_name).then_some(1.0),
+        (false, false) => Some(0.5),
+    }
+}
+
+/// Like `identify_timestamp_format`, but discards candidates whose numeric fields are
+/// impossible and scores the rest by confidence. Field checks cover the formats with a
+/// registered `ParseStrategy` (an invalid month/day/hour, or an epoch value that doesn't fit a
+/// timestamp) and the day of the `DAY_MONTH_NAME_FIELDS` formats; every other candidate is kept
+/// without a field check. Scores: `1.0` for an unambiguous match, `0.5` for a genuinely ambiguous
+/// US/EU-style date (both leading fields `<= 12`, so either order is plausible). Results are
+/// sorted highest-confidence first.
+pub fn identify_timestamp_format_validated(input: &str) -> Vec<(&'static str, f32)> {
+    let mut scored: Vec<(&'static str, f32)> = identify_timestamp_format(input)
+        .into_iter()
+        .filter_map(|name| validated_confidence(name, input).map(|score| (name, score)))
+        .collect();
+
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored
+}
+
+lazy_static! {
+    /// Unanchored copies of every `TIMESTAMP_FORMATS` pattern (the leading `^`/trailing `$`
+    /// stripped), compiled once, so a timestamp can be found as a substring of arbitrary text.
+    static ref UNANCHORED_PATTERNS: Vec<(&'static str, regex::Regex)> = FORMAT_NAMES
+        .iter()
+        .map(|name| {
+            let anchored = TIMESTAMP_FORMATS[name];
+            let unanchored = anchored.trim_start_matches('^').trim_end_matches('$');
+            (
+                *name,
+                regex::Regex::new(unanchored).expect("unanchored variant must compile"),
+            )
+        })
+        .collect();
+}
+
+/// Search `input` for the first (leftmost) substring matching any known timestamp format and
+/// return its format name, the byte range it occupied, and the remainder of `input` after it.
+/// When several formats match starting at the same leftmost offset, the longest match wins
+/// (e.g. `ISO_DATETIME` over `ISO_DATE` on `2025-05-19T14:30:15`).
+pub fn extract_timestamp(input: &str) -> Option<(&'static str, std::ops::Range<usize>, &str)> {
+    let mut best: Option<(std::ops::Range<usize>, &'static str)> = None;
+
+    for (name, regex) in UNANCHORED_PATTERNS.iter() {
+        let Some(m) = regex.find(input) else {
+            continue;
+        };
+        let is_better = match &best {
+            None => true,
+            Some((best_range, _)) => {
+                m.start() < best_range.start
+                    || (m.start() == best_range.start
+                        && m.len() > best_range.end - best_range.start)
+            }
+        };
+        if is_better {
+            best = Some((m.range(), *name));
+        }
+    }
+
+    let (range, name) = best?;
+    let remainder = &input[range.end..];
+    Some((name, range, remainder))
 }
 
 #[cfg(test)]
@@ -136,6 +856,12 @@ mod tests {
     use super::*;
     use regex_syntax::Parser;
 
+    // `set_lenient_mode`/`set_default_date_order` mutate process-global `RwLock`s
+    // (`LENIENT_MODE`/`DEFAULT_DATE_ORDER`), so any test that touches them must hold this lock
+    // for the duration — otherwise a concurrently-run test reading the same global could observe
+    // a toggle mid-flight under cargo's default parallel test execution.
+    static TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
     #[test]
     fn test_identify_iso_date() {
         let timestamp = "2025-05-19";
@@ -157,6 +883,16 @@ mod tests {
         assert!(formats.contains(&"RFC_822_1123"));
     }
 
+    #[test]
+    fn test_single_pass_matches_every_overlapping_format() {
+        // RFC_3339 and W3C_DTF both match a value with a numeric offset; the RegexSet scan should
+        // surface both in the one pass instead of stopping at the first compiled/checked pattern.
+        let timestamp = "2025-05-19T14:30:15+02:00";
+        let formats = identify_timestamp_format(timestamp);
+        assert!(formats.contains(&"RFC_3339"));
+        assert!(formats.contains(&"W3C_DTF"));
+    }
+
     #[test]
     fn test_pattern_differentiation() {
         // Test that previously identical patterns now match different strings
@@ -173,6 +909,165 @@ mod tests {
         assert!(!eu_formats.contains(&"US_DATETIME"));
     }
 
+    #[test]
+    fn test_validated_keeps_parseable_epoch_seconds() {
+        // "1716159600" is 10 digits, so it only ever matches UNIX_SECONDS-shaped patterns;
+        // every candidate chrono can actually parse should survive with full confidence.
+        let scored = identify_timestamp_format_validated("1716159600");
+        assert!(scored.iter().any(|(name, score)| *name == "UNIX_SECONDS" && *score == 1.0));
+    }
+
+    #[test]
+    fn test_validated_keeps_both_unambiguous_overlapping_formats() {
+        // RFC_3339 and W3C_DTF both match this value and both have a registered ParseStrategy, so
+        // both are genuinely valid (not ambiguous in the US/EU sense) and score 1.0.
+        let scored = identify_timestamp_format_validated("2025-05-19T14:30:15+02:00");
+        assert!(scored.iter().any(|(name, score)| *name == "RFC_3339" && *score == 1.0));
+        assert!(scored.iter().any(|(name, score)| *name == "W3C_DTF" && *score == 1.0));
+    }
+
+    #[test]
+    fn test_validated_scores_genuine_ambiguity_lower() {
+        // Both leading fields are <= 12, so US_DATETIME and EU_DATETIME are both plausible.
+        let scored = identify_timestamp_format_validated("05/07/2025 14:30:15");
+        assert!(scored.iter().any(|(name, score)| *name == "US_DATETIME" && *score == 0.5));
+        assert!(scored.iter().any(|(name, score)| *name == "EU_DATETIME" && *score == 0.5));
+    }
+
+    #[test]
+    fn test_extract_timestamp_from_log_line() {
+        let (name, range, rest) = extract_timestamp("2025-05-19 GET /api 200").unwrap();
+        assert_eq!(name, "ISO_DATE");
+        assert_eq!(range, 0..10);
+        assert_eq!(rest, " GET /api 200");
+    }
+
+    #[test]
+    fn test_extract_timestamp_prefers_longest_match_at_earliest_offset() {
+        // ISO_DATE and ISO_DATETIME both start matching at offset 0; the longer match should win.
+        let (name, range, _) = extract_timestamp("2025-05-19T14:30:15 trailing").unwrap();
+        assert_eq!(name, "ISO_DATETIME");
+        assert_eq!(range, 0..19);
+    }
+
+    #[test]
+    fn test_extract_timestamp_none_when_nothing_matches() {
+        assert!(extract_timestamp("no timestamp here").is_none());
+    }
+
+    #[test]
+    fn test_sanitize_rfc822_pads_day_and_canonicalizes_names_and_zone() {
+        let sanitized = sanitize_rfc822("mon, 9 may 2025 4:30:15 UT");
+        assert_eq!(sanitized, "Mon, 09 May 2025 04:30:15 GMT");
+    }
+
+    #[test]
+    fn test_sanitize_rfc822_passes_through_non_rfc822_shaped_input() {
+        assert_eq!(sanitize_rfc822("2025-05-19"), "2025-05-19");
+    }
+
+    #[test]
+    fn test_sanitize_pads_and_titles_and_trims_noise() {
+        let sanitized = sanitize("2025-jan-9 4:5:6 (approx)");
+        assert_eq!(sanitized, "2025-Jan-09 04:05:06");
+    }
+
+    #[test]
+    fn test_sanitize_borrows_when_already_clean() {
+        assert!(matches!(sanitize("2025-05-19"), std::borrow::Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_identify_lenient_recovers_unpadded_sql_timestamp() {
+        let formats = identify_timestamp_format_lenient("2025-05-19 4:30:15");
+        assert!(formats.contains(&"SQL_TIMESTAMP"));
+    }
+
+    #[test]
+    fn test_lenient_mode_and_call_site_lenient_helper_agree() {
+        // The process-global `LENIENT_MODE` path (via `lenient_fallback`) and the call-site-scoped
+        // `identify_timestamp_format_lenient` should recognize the same malformed input the same
+        // way now that both ultimately reach `sanitize`.
+        let _guard = TEST_LOCK.lock().unwrap();
+        let input = "2025-05-19 4:30:15";
+        set_lenient_mode(true);
+        let global = identify_timestamp_format(input);
+        set_lenient_mode(false);
+        let call_site = identify_timestamp_format_lenient(input);
+        assert_eq!(global, call_site);
+    }
+
+    #[test]
+    fn test_lenient_mode_falls_back_to_general_sanitize_when_not_rfc822_shaped() {
+        // Not RFC 822/1123-shaped at all, so `sanitize_rfc822` leaves it untouched; only the
+        // general `sanitize` (via `lenient_fallback`) pads the unpadded hour/minute/second here.
+        let _guard = TEST_LOCK.lock().unwrap();
+        set_lenient_mode(true);
+        let formats = identify_timestamp_format("2025-05-19 4:30:15");
+        assert!(formats.contains(&"SQL_TIMESTAMP"));
+        let parsed = parse_timestamp("2025-05-19 4:30:15").unwrap();
+        assert_eq!(parsed.format("%Y-%m-%d %H:%M:%S").to_string(), "2025-05-19 04:30:15");
+        set_lenient_mode(false);
+    }
+
+    #[test]
+    fn test_validated_discards_impossible_day_for_formats_without_parse_strategy() {
+        // DB2_TIMESTAMP now has a ParseStrategy, so "30 Feb" is caught by chrono directly; the
+        // other three have no ParseStrategy at all, so only `day_month_fields` catches this.
+        assert!(identify_timestamp_format_validated("2025-02-30-14.30.15.123456")
+            .iter()
+            .all(|(name, _)| *name != "DB2_TIMESTAMP"));
+        assert!(identify_timestamp_format_validated("30-FEB-25 02.30.15.1234 PM")
+            .iter()
+            .all(|(name, _)| *name != "ORACLE_TIMESTAMP"));
+        assert!(identify_timestamp_format_validated("30FEB2025:14:30:15")
+            .iter()
+            .all(|(name, _)| *name != "SAS_DATETIME"));
+        assert!(identify_timestamp_format_validated("301430Z FEB 25")
+            .iter()
+            .all(|(name, _)| *name != "AVIATION_METAR"));
+    }
+
+    #[test]
+    fn test_validated_keeps_plausible_day_for_formats_without_parse_strategy() {
+        let scored = identify_timestamp_format_validated("19-JAN-25 02.30.15.1234 PM");
+        assert!(scored.iter().any(|(name, score)| *name == "ORACLE_TIMESTAMP" && *score == 1.0));
+    }
+
+    #[test]
+    fn test_normalize_timestamp_reports_format_and_offset() {
+        let normalized = normalize_timestamp("2025-05-19T14:30:15+02:00").unwrap();
+        // Several formats share this shape (ISO_DATETIME_TZ, ISO_TZ_OFFSET, RFC_3339,
+        // W3C_DTF, ...); the one returned is whichever sorts first alphabetically among
+        // `TIMESTAMP_FORMATS`' names, which is `ISO_DATETIME_TZ`.
+        assert_eq!(normalized.format, "ISO_DATETIME_TZ");
+        assert_eq!(normalized.offset_seconds, 7200);
+        assert_eq!(normalized.epoch_nanos, 1_747_657_815_000_000_000);
+    }
+
+    #[test]
+    fn test_normalize_timestamp_assumes_utc_for_naive_calendar_formats() {
+        let normalized = normalize_timestamp("2025-05-19T14:30:15").unwrap();
+        assert_eq!(normalized.format, "ISO_DATETIME");
+        assert_eq!(normalized.offset_seconds, 0);
+    }
+
+    #[test]
+    fn test_normalize_timestamp_none_when_unrecognized() {
+        assert!(normalize_timestamp("not a timestamp").is_none());
+    }
+
+    #[test]
+    fn test_parse_timestamp_honors_configured_date_order() {
+        // "03/04/2025" is genuinely ambiguous (both fields <= 12); with DMY configured it must
+        // resolve to 3 April, not whichever of US_DATETIME/EU_DATETIME happened to parse first.
+        let _guard = TEST_LOCK.lock().unwrap();
+        set_default_date_order(DateOrder::Dmy);
+        let parsed = parse_timestamp("03/04/2025 14:30:15").unwrap();
+        assert_eq!(parsed.format("%Y-%m-%d").to_string(), "2025-04-03");
+        set_default_date_order(DateOrder::Mdy);
+    }
+
     #[test]
     fn test_detect_overlapping_patterns() {
         // Function to detect overlapping regex patterns using DFA-based analysis